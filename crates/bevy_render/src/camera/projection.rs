@@ -1,32 +1,167 @@
 use super::DepthCalculation;
-use bevy_math::Mat4;
+use bevy_math::{Mat4, Vec2, Vec3};
 use bevy_reflect::{Reflect, ReflectComponent, ReflectDeserialize};
 use serde::{Deserialize, Serialize};
 
+/// Winding convention for a projection's coordinate system. Right-handed matches
+/// the OpenGL/glTF convention (camera looks down `-Z`); left-handed is common in
+/// DirectX-authored content (camera looks down `+Z`).
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect_value(Serialize, Deserialize)]
+pub enum Handedness {
+    RightHanded,
+    LeftHanded,
+}
+
+/// Clip-space depth range a projection maps the view frustum into. `ZeroToOne`
+/// is the Vulkan/Metal/DirectX convention used by wgpu; `NegativeOneToOne` is the
+/// OpenGL convention.
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect_value(Serialize, Deserialize)]
+pub enum DepthRange {
+    ZeroToOne,
+    NegativeOneToOne,
+}
+
+/// The handedness and clip-space depth range a projection is built for. Lets a
+/// camera interoperate with assets authored under a different convention without
+/// post-multiplying a flip matrix by hand.
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect_value(Serialize, Deserialize)]
+pub struct CoordinateSystem {
+    pub handedness: Handedness,
+    pub depth_range: DepthRange,
+}
+
+impl Default for CoordinateSystem {
+    fn default() -> Self {
+        CoordinateSystem {
+            handedness: Handedness::RightHanded,
+            depth_range: DepthRange::ZeroToOne,
+        }
+    }
+}
+
+impl CoordinateSystem {
+    /// The clip-space depth of the near plane for this coordinate system's depth
+    /// range: `0` for `ZeroToOne` and `-1` for `NegativeOneToOne`.
+    fn near_clip_z(&self) -> f32 {
+        match self.depth_range {
+            DepthRange::ZeroToOne => 0.0,
+            DepthRange::NegativeOneToOne => -1.0,
+        }
+    }
+
+    /// Remaps a `[0, 1]` clip-space matrix into this coordinate system's depth
+    /// range, leaving `ZeroToOne` matrices untouched.
+    fn apply_depth_range(&self, zero_to_one: Mat4) -> Mat4 {
+        match self.depth_range {
+            DepthRange::ZeroToOne => zero_to_one,
+            DepthRange::NegativeOneToOne => {
+                // Scale and bias clip-space z from [0, 1] to [-1, 1].
+                let remap = Mat4::from_cols_array(&[
+                    1.0, 0.0, 0.0, 0.0, // col 0
+                    0.0, 1.0, 0.0, 0.0, // col 1
+                    0.0, 0.0, 2.0, 0.0, // col 2
+                    0.0, 0.0, -1.0, 1.0, // col 3
+                ]);
+                remap * zero_to_one
+            }
+        }
+    }
+}
+
 pub trait CameraProjection {
     fn get_projection_matrix(&self) -> Mat4;
     fn update(&mut self, width: f32, height: f32);
     fn depth_calculation(&self) -> DepthCalculation;
+
+    /// The clip-space depth of the near plane, which depends on the projection's
+    /// depth-range convention: `0` for a `[0, 1]` range (wgpu) and `-1` for a
+    /// `[-1, 1]` range (OpenGL). Defaults to the wgpu convention; projections
+    /// carrying a [`CoordinateSystem`] override this to match their depth range.
+    fn near_clip_z(&self) -> f32 {
+        0.0
+    }
+
+    /// Unprojects a screen-space position into a world-space ray.
+    ///
+    /// `camera_transform` is the camera's world transform (the view matrix is its
+    /// inverse), `screen_pos` is a pixel position with a top-left origin, and
+    /// `viewport_size` is the size of the render target in pixels. Returns the ray
+    /// origin on the near plane (unprojected at [`near_clip_z`](CameraProjection::near_clip_z))
+    /// and its normalized direction. For orthographic projections the near and far
+    /// points differ only by the camera forward axis, so the returned direction is
+    /// the camera's forward direction.
+    fn screen_to_ray(
+        &self,
+        camera_transform: &Mat4,
+        screen_pos: Vec2,
+        viewport_size: Vec2,
+    ) -> (Vec3, Vec3) {
+        let view_proj = self.get_projection_matrix() * camera_transform.inverse();
+        let inverse_view_proj = view_proj.inverse();
+        let ndc_x = 2.0 * screen_pos.x / viewport_size.x - 1.0;
+        let ndc_y = 1.0 - 2.0 * screen_pos.y / viewport_size.y;
+        let near = inverse_view_proj.project_point3(Vec3::new(ndc_x, ndc_y, self.near_clip_z()));
+        let far = inverse_view_proj.project_point3(Vec3::new(ndc_x, ndc_y, 1.0));
+        (near, (far - near).normalize())
+    }
+
+    /// Projects a world-space position into a screen-space pixel position.
+    ///
+    /// This is the inverse of [`screen_to_ray`](CameraProjection::screen_to_ray):
+    /// `world_pos` is mapped through `projection * camera_transform.inverse()` and
+    /// the resulting normalized device coordinates are scaled to `viewport_size`
+    /// using a top-left pixel origin.
+    fn world_to_screen(
+        &self,
+        camera_transform: &Mat4,
+        world_pos: Vec3,
+        viewport_size: Vec2,
+    ) -> Vec2 {
+        let view_proj = self.get_projection_matrix() * camera_transform.inverse();
+        let ndc = view_proj.project_point3(world_pos);
+        Vec2::new(
+            (ndc.x + 1.0) * 0.5 * viewport_size.x,
+            (1.0 - ndc.y) * 0.5 * viewport_size.y,
+        )
+    }
 }
 
-#[derive(Debug, Clone, Reflect)]
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
 #[reflect(Component)]
 pub struct PerspectiveProjection {
     pub fov: f32,
     pub aspect_ratio: f32,
     pub near: f32,
     pub far: f32,
+    pub coordinate_system: CoordinateSystem,
 }
 
 impl CameraProjection for PerspectiveProjection {
     fn get_projection_matrix(&self) -> Mat4 {
-        Mat4::perspective_rh(self.fov, self.aspect_ratio, self.near, self.far)
+        let projection = match self.coordinate_system.handedness {
+            Handedness::RightHanded => {
+                Mat4::perspective_rh(self.fov, self.aspect_ratio, self.near, self.far)
+            }
+            Handedness::LeftHanded => {
+                Mat4::perspective_lh(self.fov, self.aspect_ratio, self.near, self.far)
+            }
+        };
+        self.coordinate_system.apply_depth_range(projection)
     }
 
     fn update(&mut self, width: f32, height: f32) {
         self.aspect_ratio = width / height;
     }
 
+    fn near_clip_z(&self) -> f32 {
+        self.coordinate_system.near_clip_z()
+    }
+
+    // View-space distance sorting is independent of the clip-space convention, so
+    // this does not branch on `coordinate_system`.
     fn depth_calculation(&self) -> DepthCalculation {
         DepthCalculation::Distance
     }
@@ -39,6 +174,98 @@ impl Default for PerspectiveProjection {
             near: 1.0,
             far: 1000.0,
             aspect_ratio: 1.0,
+            coordinate_system: CoordinateSystem::default(),
+        }
+    }
+}
+
+/// Builds a right-handed projection matrix from an arbitrary (possibly
+/// asymmetric) view frustum, using the same `[0, 1]` depth convention as
+/// [`Mat4::perspective_rh`]. Unlike the symmetric `perspective_rh`, the near
+/// plane extents are given explicitly, which lets the frustum be off-center.
+fn frustum_rh(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Mat4 {
+    let two_near = 2.0 * near;
+    let inv_width = 1.0 / (right - left);
+    let inv_height = 1.0 / (top - bottom);
+    let inv_depth = 1.0 / (near - far);
+    Mat4::from_cols_array(&[
+        two_near * inv_width,
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        two_near * inv_height,
+        0.0,
+        0.0,
+        (right + left) * inv_width,
+        (top + bottom) * inv_height,
+        far * inv_depth,
+        -1.0,
+        0.0,
+        0.0,
+        near * far * inv_depth,
+        0.0,
+    ])
+}
+
+/// A physically-based pinhole camera projection parameterized by intrinsic
+/// parameters, as produced by camera calibration or computer-vision pipelines.
+///
+/// The focal lengths `fx`/`fy` and principal point `cx`/`cy` are expressed in
+/// pixels relative to a sensor of `width` by `height` pixels, mirroring the
+/// intrinsic matrix `K`. A non-centered principal point produces an asymmetric
+/// (off-center) frustum, and distinct `fx`/`fy` describe non-square pixels,
+/// allowing Bevy renders to be overlaid on real camera footage.
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+pub struct PinholeProjection {
+    pub fx: f32,
+    pub fy: f32,
+    pub cx: f32,
+    pub cy: f32,
+    pub width: f32,
+    pub height: f32,
+    pub near: f32,
+    pub far: f32,
+}
+
+impl PinholeProjection {
+    /// The mean focal length in pixels, averaging the horizontal and vertical
+    /// axes for cameras with non-square pixels.
+    pub fn focal_length(&self) -> f32 {
+        (self.fx + self.fy) / 2.0
+    }
+}
+
+impl CameraProjection for PinholeProjection {
+    fn get_projection_matrix(&self) -> Mat4 {
+        let right = self.near * (self.width - self.cx) / self.fx;
+        let left = -self.near * self.cx / self.fx;
+        let top = self.near * (self.height - self.cy) / self.fy;
+        let bottom = -self.near * self.cy / self.fy;
+        frustum_rh(left, right, bottom, top, self.near, self.far)
+    }
+
+    // The intrinsics describe a fixed physical sensor, so the projection is
+    // resolution-independent and does not react to window resizes.
+    fn update(&mut self, _width: f32, _height: f32) {}
+
+    fn depth_calculation(&self) -> DepthCalculation {
+        DepthCalculation::Distance
+    }
+}
+
+impl Default for PinholeProjection {
+    fn default() -> Self {
+        PinholeProjection {
+            fx: 1.0,
+            fy: 1.0,
+            cx: 0.5,
+            cy: 0.5,
+            width: 1.0,
+            height: 1.0,
+            near: 1.0,
+            far: 1000.0,
         }
     }
 }
@@ -51,7 +278,31 @@ pub enum WindowOrigin {
     BottomLeft,
 }
 
-#[derive(Debug, Clone, Reflect)]
+/// Controls how an [`OrthographicProjection`] responds to the size of its render
+/// target. Apart from [`WindowSize`](ScalingMode::WindowSize), every mode is
+/// resolution-independent: the visible world size is derived from the mode and
+/// the viewport aspect ratio rather than from raw pixel counts.
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect_value(Serialize, Deserialize)]
+pub enum ScalingMode {
+    /// Map one world unit to one pixel, so the visible world size equals the
+    /// render-target size in pixels. This is the classic pixel-space 2D camera.
+    WindowSize,
+    /// A fixed visible world size that does not react to the window size.
+    Fixed(Vec2),
+    /// Lock the horizontal axis to a world width and derive the height from the
+    /// viewport aspect ratio.
+    FitHorizontal(f32),
+    /// Lock the vertical axis to a world height and derive the width from the
+    /// viewport aspect ratio.
+    FitVertical(f32),
+    /// Fit a fixed world rectangle to the view. When `fit_inside` is true the
+    /// rectangle is fully contained (letterboxed); otherwise it fully covers the
+    /// view (cropped).
+    FitToView { size: Vec2, fit_inside: bool },
+}
+
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
 #[reflect(Component)]
 pub struct OrthographicProjection {
     pub left: f32,
@@ -61,25 +312,59 @@ pub struct OrthographicProjection {
     pub near: f32,
     pub far: f32,
     pub window_origin: WindowOrigin,
+    pub scaling_mode: ScalingMode,
+    pub coordinate_system: CoordinateSystem,
 }
 
 impl CameraProjection for OrthographicProjection {
     fn get_projection_matrix(&self) -> Mat4 {
-        Mat4::orthographic_rh(
-            self.left,
-            self.right,
-            self.bottom,
-            self.top,
-            self.near,
-            self.far,
-        )
+        let projection = match self.coordinate_system.handedness {
+            Handedness::RightHanded => Mat4::orthographic_rh(
+                self.left,
+                self.right,
+                self.bottom,
+                self.top,
+                self.near,
+                self.far,
+            ),
+            Handedness::LeftHanded => Mat4::orthographic_lh(
+                self.left,
+                self.right,
+                self.bottom,
+                self.top,
+                self.near,
+                self.far,
+            ),
+        };
+        self.coordinate_system.apply_depth_range(projection)
     }
 
     fn update(&mut self, width: f32, height: f32) {
+        let (visible_width, visible_height) = match self.scaling_mode {
+            ScalingMode::WindowSize => (width, height),
+            ScalingMode::Fixed(size) => (size.x, size.y),
+            ScalingMode::FitHorizontal(world_width) => {
+                (world_width, world_width * height / width)
+            }
+            ScalingMode::FitVertical(world_height) => {
+                (world_height * width / height, world_height)
+            }
+            ScalingMode::FitToView { size, fit_inside } => {
+                let scale_x = width / size.x;
+                let scale_y = height / size.y;
+                let scale = if fit_inside {
+                    scale_x.min(scale_y)
+                } else {
+                    scale_x.max(scale_y)
+                };
+                (width / scale, height / scale)
+            }
+        };
+
         match self.window_origin {
             WindowOrigin::Center => {
-                let half_width = width / 2.0;
-                let half_height = height / 2.0;
+                let half_width = visible_width / 2.0;
+                let half_height = visible_height / 2.0;
                 self.left = -half_width;
                 self.right = half_width;
                 self.top = half_height;
@@ -87,13 +372,19 @@ impl CameraProjection for OrthographicProjection {
             }
             WindowOrigin::BottomLeft => {
                 self.left = 0.0;
-                self.right = width;
-                self.top = height;
+                self.right = visible_width;
+                self.top = visible_height;
                 self.bottom = 0.0;
             }
         }
     }
 
+    fn near_clip_z(&self) -> f32 {
+        self.coordinate_system.near_clip_z()
+    }
+
+    // The z-difference is taken in view space and so stays the same under either
+    // handedness or depth range.
     fn depth_calculation(&self) -> DepthCalculation {
         DepthCalculation::ZDifference
     }
@@ -109,6 +400,8 @@ impl Default for OrthographicProjection {
             near: 0.0,
             far: 1000.0,
             window_origin: WindowOrigin::Center,
+            scaling_mode: ScalingMode::WindowSize,
+            coordinate_system: CoordinateSystem::default(),
         }
     }
 }
@@ -120,7 +413,7 @@ pub enum BaseAxis {
     Horizontal,
 }
 
-#[derive(Debug, Clone, Reflect)]
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
 #[reflect(Component)]
 pub struct ScaledOrthographicProjection {
     pub scale: f32,
@@ -129,44 +422,40 @@ pub struct ScaledOrthographicProjection {
     pub far: f32,
     pub window_origin: WindowOrigin,
     pub base_axis: BaseAxis,
+    pub coordinate_system: CoordinateSystem,
 }
 
 impl CameraProjection for ScaledOrthographicProjection {
     fn get_projection_matrix(&self) -> Mat4 {
-        match (&self.window_origin, &self.base_axis) {
-            (WindowOrigin::Center, BaseAxis::Vertical) => Mat4::orthographic_rh(
+        let (left, right, bottom, top) = match (&self.window_origin, &self.base_axis) {
+            (WindowOrigin::Center, BaseAxis::Vertical) => (
                 -self.aspect_ratio * self.scale,
                 self.aspect_ratio * self.scale,
                 -self.scale,
                 self.scale,
-                self.near,
-                self.far,
-            ),
-            (WindowOrigin::BottomLeft, BaseAxis::Vertical) => Mat4::orthographic_rh(
-                0.0,
-                self.aspect_ratio * self.scale,
-                0.0,
-                self.scale,
-                self.near,
-                self.far,
             ),
-            (WindowOrigin::Center, BaseAxis::Horizontal) => Mat4::orthographic_rh(
+            (WindowOrigin::BottomLeft, BaseAxis::Vertical) => {
+                (0.0, self.aspect_ratio * self.scale, 0.0, self.scale)
+            }
+            (WindowOrigin::Center, BaseAxis::Horizontal) => (
                 -self.scale,
                 self.scale,
                 -self.aspect_ratio * self.scale,
                 self.aspect_ratio * self.scale,
-                self.near,
-                self.far,
             ),
-            (WindowOrigin::BottomLeft, BaseAxis::Horizontal) => Mat4::orthographic_rh(
-                0.0,
-                self.scale,
-                0.0,
-                self.aspect_ratio * self.scale,
-                self.near,
-                self.far,
-            ),
-        }
+            (WindowOrigin::BottomLeft, BaseAxis::Horizontal) => {
+                (0.0, self.scale, 0.0, self.aspect_ratio * self.scale)
+            }
+        };
+        let projection = match self.coordinate_system.handedness {
+            Handedness::RightHanded => {
+                Mat4::orthographic_rh(left, right, bottom, top, self.near, self.far)
+            }
+            Handedness::LeftHanded => {
+                Mat4::orthographic_lh(left, right, bottom, top, self.near, self.far)
+            }
+        };
+        self.coordinate_system.apply_depth_range(projection)
     }
 
     fn update(&mut self, width: f32, height: f32) {
@@ -176,6 +465,12 @@ impl CameraProjection for ScaledOrthographicProjection {
         }
     }
 
+    fn near_clip_z(&self) -> f32 {
+        self.coordinate_system.near_clip_z()
+    }
+
+    // As with the other projections, sorting happens in view space, so the chosen
+    // coordinate system does not affect the calculation.
     fn depth_calculation(&self) -> DepthCalculation {
         DepthCalculation::ZDifference
     }
@@ -190,6 +485,262 @@ impl Default for ScaledOrthographicProjection {
             far: 1000.0,
             window_origin: WindowOrigin::Center,
             base_axis: BaseAxis::Vertical,
+            coordinate_system: CoordinateSystem::default(),
+        }
+    }
+}
+
+/// Identifies which variant a [`Projection`] currently holds, without borrowing
+/// the contained projection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+#[reflect_value(Serialize, Deserialize)]
+pub enum ProjectionKind {
+    Perspective,
+    Orthographic,
+    ScaledOrthographic,
+    Pinhole,
+}
+
+/// A camera projection stored as a plain component enum rather than a
+/// `Box<dyn CameraProjection>`, so it can be reflected, serialized, and swapped
+/// between perspective and orthographic at runtime by mutating a single
+/// component. It implements [`CameraProjection`] by delegating to the active
+/// variant.
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+#[reflect_value(Component, Serialize, Deserialize)]
+pub enum Projection {
+    Perspective(PerspectiveProjection),
+    Orthographic(OrthographicProjection),
+    ScaledOrthographic(ScaledOrthographicProjection),
+    Pinhole(PinholeProjection),
+}
+
+impl Projection {
+    /// Returns which variant is currently active.
+    pub fn kind(&self) -> ProjectionKind {
+        match self {
+            Projection::Perspective(_) => ProjectionKind::Perspective,
+            Projection::Orthographic(_) => ProjectionKind::Orthographic,
+            Projection::ScaledOrthographic(_) => ProjectionKind::ScaledOrthographic,
+            Projection::Pinhole(_) => ProjectionKind::Pinhole,
+        }
+    }
+
+    /// Toggles the camera between a default 3D perspective and a default 2D
+    /// orthographic projection. Any perspective-like variant becomes
+    /// orthographic and any orthographic-like variant becomes perspective.
+    pub fn toggle(&mut self) {
+        *self = match self {
+            Projection::Perspective(_) | Projection::Pinhole(_) => {
+                Projection::Orthographic(OrthographicProjection::default())
+            }
+            Projection::Orthographic(_) | Projection::ScaledOrthographic(_) => {
+                Projection::Perspective(PerspectiveProjection::default())
+            }
+        };
+    }
+}
+
+impl Default for Projection {
+    fn default() -> Self {
+        Projection::Perspective(PerspectiveProjection::default())
+    }
+}
+
+impl CameraProjection for Projection {
+    fn get_projection_matrix(&self) -> Mat4 {
+        match self {
+            Projection::Perspective(projection) => projection.get_projection_matrix(),
+            Projection::Orthographic(projection) => projection.get_projection_matrix(),
+            Projection::ScaledOrthographic(projection) => projection.get_projection_matrix(),
+            Projection::Pinhole(projection) => projection.get_projection_matrix(),
+        }
+    }
+
+    fn update(&mut self, width: f32, height: f32) {
+        match self {
+            Projection::Perspective(projection) => projection.update(width, height),
+            Projection::Orthographic(projection) => projection.update(width, height),
+            Projection::ScaledOrthographic(projection) => projection.update(width, height),
+            Projection::Pinhole(projection) => projection.update(width, height),
+        }
+    }
+
+    fn near_clip_z(&self) -> f32 {
+        match self {
+            Projection::Perspective(projection) => projection.near_clip_z(),
+            Projection::Orthographic(projection) => projection.near_clip_z(),
+            Projection::ScaledOrthographic(projection) => projection.near_clip_z(),
+            Projection::Pinhole(projection) => projection.near_clip_z(),
+        }
+    }
+
+    fn depth_calculation(&self) -> DepthCalculation {
+        match self {
+            Projection::Perspective(projection) => projection.depth_calculation(),
+            Projection::Orthographic(projection) => projection.depth_calculation(),
+            Projection::ScaledOrthographic(projection) => projection.depth_calculation(),
+            Projection::Pinhole(projection) => projection.depth_calculation(),
         }
     }
 }
+
+impl From<PerspectiveProjection> for Projection {
+    fn from(projection: PerspectiveProjection) -> Self {
+        Projection::Perspective(projection)
+    }
+}
+
+impl From<OrthographicProjection> for Projection {
+    fn from(projection: OrthographicProjection) -> Self {
+        Projection::Orthographic(projection)
+    }
+}
+
+impl From<ScaledOrthographicProjection> for Projection {
+    fn from(projection: ScaledOrthographicProjection) -> Self {
+        Projection::ScaledOrthographic(projection)
+    }
+}
+
+impl From<PinholeProjection> for Projection {
+    fn from(projection: PinholeProjection) -> Self {
+        Projection::Pinhole(projection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1.0e-4,
+            "{} is not approximately {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn window_size_scaling_maps_world_to_pixels() {
+        let mut projection = OrthographicProjection::default();
+        projection.update(800.0, 600.0);
+        assert_approx(projection.left, -400.0);
+        assert_approx(projection.right, 400.0);
+        assert_approx(projection.bottom, -300.0);
+        assert_approx(projection.top, 300.0);
+    }
+
+    #[test]
+    fn fit_to_view_contains_and_covers() {
+        let mut contain = OrthographicProjection {
+            scaling_mode: ScalingMode::FitToView {
+                size: Vec2::new(10.0, 10.0),
+                fit_inside: true,
+            },
+            ..Default::default()
+        };
+        contain.update(200.0, 100.0);
+        // Letterboxed: the shorter axis binds, so the full target is visible.
+        assert_approx(contain.right - contain.left, 20.0);
+        assert_approx(contain.top - contain.bottom, 10.0);
+
+        let mut cover = OrthographicProjection {
+            scaling_mode: ScalingMode::FitToView {
+                size: Vec2::new(10.0, 10.0),
+                fit_inside: false,
+            },
+            ..Default::default()
+        };
+        cover.update(200.0, 100.0);
+        // Cropped: the longer axis binds, so the target fully covers the view.
+        assert_approx(cover.right - cover.left, 10.0);
+        assert_approx(cover.top - cover.bottom, 5.0);
+    }
+
+    #[test]
+    fn screen_to_ray_points_forward_from_near_plane() {
+        let projection = PerspectiveProjection::default();
+        let viewport = Vec2::new(800.0, 600.0);
+        let (origin, direction) =
+            projection.screen_to_ray(&Mat4::IDENTITY, viewport / 2.0, viewport);
+        // A centered pixel looks straight down the camera forward axis (-Z for RH).
+        assert_approx(direction.x, 0.0);
+        assert_approx(direction.y, 0.0);
+        assert_approx(direction.z, -1.0);
+        // The origin lies on the near plane regardless of depth-range convention.
+        assert_approx(origin.z, -projection.near);
+    }
+
+    #[test]
+    fn screen_to_ray_origin_tracks_depth_range() {
+        let mut projection = PerspectiveProjection::default();
+        projection.coordinate_system.depth_range = DepthRange::NegativeOneToOne;
+        let viewport = Vec2::new(800.0, 600.0);
+        let (origin, _) = projection.screen_to_ray(&Mat4::IDENTITY, viewport / 2.0, viewport);
+        assert_approx(origin.z, -projection.near);
+    }
+
+    #[test]
+    fn depth_range_maps_near_plane_clip_z() {
+        // Default near = 1.0, with the camera looking down -Z.
+        let view_point = Vec3::new(0.0, 0.0, -1.0);
+        let mut projection = PerspectiveProjection::default();
+
+        // wgpu convention: the near plane maps to clip-space z = 0.
+        let ndc = projection.get_projection_matrix().project_point3(view_point);
+        assert_approx(ndc.z, 0.0);
+
+        // OpenGL convention: the same near plane maps to clip-space z = -1.
+        projection.coordinate_system.depth_range = DepthRange::NegativeOneToOne;
+        let ndc = projection.get_projection_matrix().project_point3(view_point);
+        assert_approx(ndc.z, -1.0);
+    }
+
+    #[test]
+    fn pinhole_principal_point_shifts_frustum() {
+        let centered = PinholeProjection {
+            fx: 100.0,
+            fy: 100.0,
+            cx: 100.0,
+            cy: 50.0,
+            width: 200.0,
+            height: 100.0,
+            near: 1.0,
+            far: 1000.0,
+        };
+        let ahead = Vec3::new(0.0, 0.0, -1.0);
+
+        // A centered principal point keeps the optical axis at the NDC origin, and
+        // the near plane maps to clip-space z = 0.
+        let ndc = centered.get_projection_matrix().project_point3(ahead);
+        assert_approx(ndc.x, 0.0);
+        assert_approx(ndc.y, 0.0);
+        assert_approx(ndc.z, 0.0);
+
+        // The far plane maps to clip-space z = 1.
+        let far_ndc = centered
+            .get_projection_matrix()
+            .project_point3(Vec3::new(0.0, 0.0, -centered.far));
+        assert_approx(far_ndc.z, 1.0);
+
+        // Offsetting the principal point shifts the optical axis in NDC.
+        let shifted = PinholeProjection {
+            cx: 150.0,
+            ..centered.clone()
+        };
+        let ndc = shifted.get_projection_matrix().project_point3(ahead);
+        assert_approx(ndc.x, 0.5);
+    }
+
+    #[test]
+    fn pinhole_focal_length_averages_axes() {
+        let projection = PinholeProjection {
+            fx: 100.0,
+            fy: 120.0,
+            ..Default::default()
+        };
+        assert_approx(projection.focal_length(), 110.0);
+    }
+}